@@ -1,13 +1,18 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::fs::File;
-use std::io::Write;
-use std::os::fd::FromRawFd;
+use std::io::{self, Read, Write};
+use std::net::TcpStream;
+use std::os::fd::{AsRawFd, FromRawFd};
+use std::os::unix::fs::FileExt;
 use std::sync::{Arc, Mutex};
 use std::thread::{self, JoinHandle};
 
-use crossbeam_channel::{Sender, Receiver, unbounded};
+use chacha20::ChaCha20;
+use chacha20::cipher::{KeyIvInit, StreamCipher};
+use crossbeam_channel::{Sender, Receiver, TryRecvError, TrySendError, bounded};
 use once_cell::sync::Lazy;
 use pyo3::buffer::PyBuffer;
+use pyo3::exceptions::{PyIOError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::types::PyMemoryView;
 
@@ -17,23 +22,99 @@ static READ_THREAD_COUNT: Lazy<usize> = Lazy::new(|| {
         .unwrap_or(4)
 });
 
+// Caps how many in-flight frames each queue (the global dispatch queue, and
+// every per-fd writer channel and its backlog) may hold before `pipe()` blocks, so a slow
+// consumer can't make Python pile up unbounded `Vec<u8>` copies in memory.
+static QUEUE_CAPACITY: Lazy<usize> = Lazy::new(|| {
+    std::env::var("TURBOPIPE_QUEUE_CAPACITY").ok()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(64)
+});
+
 type FileDescriptor = i32;
 type Pointer = usize;
-type Frame = Vec<u8>;
+
+struct Frame {
+    data: Vec<u8>,
+    offset: Option<u64>,
+}
 
 #[derive(Clone)]
 struct Work {
     data: Pointer,
     size: usize,
     file: FileDescriptor,
+    offset: Option<u64>,
+}
+
+/// A destination a writer thread drains frames into. The fd/file path is
+/// just the original implementation of this trait; sockets (and, in time,
+/// other transports) are others.
+trait FrameSink: Write + Send {
+    fn write_frame(&mut self, data: &[u8]) -> io::Result<()> {
+        self.write_all(data)
+    }
+
+    fn write_frame_at(&mut self, data: &[u8], offset: u64) -> io::Result<()> {
+        let _ = offset;
+        Err(io::Error::new(io::ErrorKind::Unsupported, "positional writes are not supported by this sink"))
+    }
+
+    /// Called once the channel closes, in place of an ordinary `Drop`, so
+    /// sinks that don't own their underlying fd (see `File`) can opt out of
+    /// closing it.
+    fn finish(self: Box<Self>) {}
+}
+
+impl FrameSink for File {
+    fn write_frame_at(&mut self, data: &[u8], offset: u64) -> io::Result<()> {
+        self.write_all_at(data, offset)
+    }
+
+    fn finish(self: Box<Self>) {
+        // Python owns this fd; don't close it when the writer thread exits.
+        std::mem::forget(self);
+    }
+}
+
+impl FrameSink for TcpStream {
+    fn write_frame(&mut self, data: &[u8]) -> io::Result<()> {
+        self.write_all(&(data.len() as u32).to_be_bytes())?;
+        self.write_all(data)
+    }
 }
 
 type PendingPointers = Arc<Mutex<HashMap<FileDescriptor, HashSet<Pointer>>>>;
 type StreamsMap = Arc<Mutex<HashMap<FileDescriptor, EternalWriter>>>;
+type ReadersMap = Arc<Mutex<HashMap<FileDescriptor, FrameReader>>>;
+
+/// The read-side counterpart of `EternalWriter`: a background thread tails
+/// `file` into bounded chunks, and `leftover` holds the part of the last
+/// chunk taken from the channel that hasn't been copied out to Python yet.
+struct FrameReader {
+    receiver: Receiver<Vec<u8>>,
+    handle: JoinHandle<()>,
+    leftover: Vec<u8>,
+    error: Arc<Mutex<Option<io::Error>>>,
+}
 
 struct EternalWriter {
     sender: Sender<Frame>,
+    // Holds frames a reader thread couldn't hand to `sender` without blocking
+    // because the channel was already full. Keeping these here, per fd,
+    // instead of routing them back through the shared dispatch queue, keeps
+    // this stream's frames in hand-over order: once a frame lands in `backlog`,
+    // every later frame for this fd is queued behind it here too, rather
+    // than racing it through `sender` out of order. See `eternal_reader` and
+    // `eternal_writer`.
+    backlog: Arc<Mutex<VecDeque<Frame>>>,
     handle: JoinHandle<()>,
+    error: Arc<Mutex<Option<io::Error>>>,
+    cipher: Arc<Mutex<Option<ChaCha20>>>,
+    // Set once a `pipe_at` targets this stream. ChaCha20's keystream only
+    // advances correctly when frames are encrypted in strict byte-offset
+    // order, which `pipe_at` exists to defeat, so the two can't be combined.
+    offset_used: Arc<Mutex<bool>>,
 }
 
 struct EternalReader {
@@ -46,29 +127,57 @@ struct TurboPipe {
     queue: Sender<Work>,
     pending: PendingPointers,
     streams: StreamsMap,
+    readers: ReadersMap,
 }
 
 impl TurboPipe {
     pub fn new() -> Self {
         let pending = Arc::new(Mutex::new(HashMap::new()));
         let streams = Arc::new(Mutex::new(HashMap::new()));
-        let (queue, queue_rx) = unbounded();
+        let readers = Arc::new(Mutex::new(HashMap::new()));
+        let (queue, queue_rx) = bounded(*QUEUE_CAPACITY);
         for _ in 0..*READ_THREAD_COUNT {
+            let pending = pending.clone();
+            let streams = streams.clone();
+            let queue_rx = queue_rx.clone();
             thread::spawn(move || Self::eternal_reader(EternalReader{
-                pending: pending.clone(),
-                streams: streams.clone(),
-                queue: queue_rx.clone(),
+                pending,
+                streams,
+                queue: queue_rx,
             }));
         }
-        Self {queue, pending, streams}
+        Self {queue, pending, streams, readers}
     }
 
     fn eternal_reader(this: EternalReader) {
         while let Ok(work) = this.queue.recv() {
-            let data = unsafe { std::slice::from_raw_parts(work.data as *const u8, work.size).to_vec() };
-            let sender = this.streams.lock().unwrap().get(&work.file).map(|w| w.sender.clone());
-            if let Some(sender) = sender {
-                sender.send(data).unwrap();
+            let writer = this.streams.lock().unwrap().get(&work.file)
+                .map(|w| (w.sender.clone(), w.backlog.clone()));
+            if let Some((sender, backlog)) = writer {
+                let data = unsafe { std::slice::from_raw_parts(work.data as *const u8, work.size).to_vec() };
+                let frame = Frame { data, offset: work.offset };
+                // `READ_THREAD_COUNT` reader threads are shared across every
+                // open stream, so blocking here to wait out a full channel
+                // would tie up one of that small shared pool for as long as
+                // this one destination stays slow, starving unrelated
+                // streams too. `try_send` instead, and if the channel is
+                // full, stash the frame in this fd's own backlog rather than
+                // handing it back to the global dispatch queue: going back
+                // through the shared queue could let a later frame for this
+                // same fd reach another idle reader thread first, reordering
+                // the stream. Once the backlog holds anything, every further
+                // frame for this fd is queued behind it here too, so the
+                // backlog keeps frames in the order they reach it. Two frames
+                // of one fd picked up by two reader threads at once may still
+                // reach it in either order.
+                let mut backlog = backlog.lock().unwrap();
+                if backlog.is_empty() {
+                    if let Err(TrySendError::Full(frame)) = sender.try_send(frame) {
+                        backlog.push_back(frame);
+                    }
+                } else {
+                    backlog.push_back(frame);
+                }
             }
             let mut p = this.pending.lock().unwrap();
             if let Some(set) = p.get_mut(&work.file) {
@@ -77,12 +186,77 @@ impl TurboPipe {
         }
     }
 
-    fn eternal_writer(rx: Receiver<Frame>, file: FileDescriptor) {
-        let mut file = unsafe { File::from_raw_fd(file) };
-        while let Ok(data) = rx.recv() {
-            let _ = file.write_all(&data);
+    fn eternal_writer(
+        rx: Receiver<Frame>,
+        mut sink: Box<dyn FrameSink>,
+        error: Arc<Mutex<Option<io::Error>>>,
+        cipher: Arc<Mutex<Option<ChaCha20>>>,
+        backlog: Arc<Mutex<VecDeque<Frame>>>,
+    ) {
+        loop {
+            // Anything already sitting in `rx` was handed off before this
+            // fd's backlog started accumulating, so it's older and must be
+            // written first; only fall through to the backlog once `rx` is
+            // drained. `close()` waits for `pending` to empty before
+            // dropping the sender, so once `rx` disconnects, no reader
+            // thread can still be mid-dispatch for this fd — the backlog is
+            // stable and just needs draining before the sink closes.
+            let mut frame = match rx.try_recv() {
+                Ok(frame) => frame,
+                Err(TryRecvError::Empty) => match backlog.lock().unwrap().pop_front() {
+                    Some(frame) => frame,
+                    None => match rx.recv() {
+                        Ok(frame) => frame,
+                        Err(_) => break,
+                    },
+                },
+                Err(TryRecvError::Disconnected) => match backlog.lock().unwrap().pop_front() {
+                    Some(frame) => frame,
+                    None => break,
+                },
+            };
+            // Once the sink is known broken, keep draining without retrying
+            // the write; the first error is what gets reported.
+            if error.lock().unwrap().is_some() {
+                continue;
+            }
+            if let Some(cipher) = cipher.lock().unwrap().as_mut() {
+                cipher.apply_keystream(&mut frame.data);
+            }
+            let result = match frame.offset {
+                Some(offset) => sink.write_frame_at(&frame.data, offset),
+                None => sink.write_frame(&frame.data),
+            };
+            if let Err(err) = result {
+                *error.lock().unwrap() = Some(err);
+            }
         }
-        std::mem::forget(file);
+        sink.finish();
+    }
+
+    /// Spawns the writer thread for `file` over `sink`, unless one is
+    /// already registered. Must be called with `self.streams` already held
+    /// so the check-then-insert is atomic.
+    fn insert_writer(
+        streams: &mut HashMap<FileDescriptor, EternalWriter>,
+        file: FileDescriptor,
+        sink: Box<dyn FrameSink>,
+    ) {
+        if streams.contains_key(&file) {
+            return;
+        }
+        let (tx, rx) = bounded(*QUEUE_CAPACITY);
+        let error = Arc::new(Mutex::new(None));
+        let cipher = Arc::new(Mutex::new(None));
+        let offset_used = Arc::new(Mutex::new(false));
+        let backlog = Arc::new(Mutex::new(VecDeque::new()));
+        let handle = thread::spawn({
+            let error = error.clone();
+            let cipher = cipher.clone();
+            let backlog = backlog.clone();
+            move || Self::eternal_writer(rx, sink, error, cipher, backlog)
+        });
+        streams.insert(file, EternalWriter {sender: tx, backlog, handle, error, cipher, offset_used});
     }
 
     fn make_stream(&self, file: FileDescriptor) {
@@ -90,13 +264,86 @@ impl TurboPipe {
         if streams.contains_key(&file) {
             return;
         }
-        let (tx, rx) = unbounded();
-        let handle = thread::spawn(move || Self::eternal_writer(rx, file));
-        streams.insert(file, EternalWriter {sender: tx, handle});
+        let sink: Box<dyn FrameSink> = Box::new(unsafe { File::from_raw_fd(file) });
+        Self::insert_writer(&mut streams, file, sink);
+    }
+
+    /// Registers a TCP sink and returns the fd callers should use with the
+    /// existing `pipe`/`sync`/`close` machinery, turning the socket into
+    /// just another `FrameSink`-backed stream. Frames are length-prefixed on
+    /// the wire so a remote process can reassemble them.
+    pub fn pipe_to_socket(&self, host: &str, port: u16) -> PyResult<FileDescriptor> {
+        let stream = TcpStream::connect((host, port))
+            .map_err(|err| PyIOError::new_err(err.to_string()))?;
+        let file = stream.as_raw_fd();
+        let mut streams = self.streams.lock().unwrap();
+        Self::insert_writer(&mut streams, file, Box::new(stream));
+        Ok(file)
+    }
+
+    /// Makes every subsequent frame written to `file` pass through a ChaCha20
+    /// keystream first, continuing the cipher's position across frames so
+    /// the stream is decryptable as one continuous ciphertext. Rejected once
+    /// `file` has taken a `pipe_at` offset write, since out-of-order frames
+    /// would desync the keystream from the plaintext's actual positions.
+    fn set_cipher(&self, file: FileDescriptor, key: &[u8], nonce: &[u8]) -> PyResult<()> {
+        let cipher = ChaCha20::new_from_slices(key, nonce)
+            .map_err(|err| PyValueError::new_err(err.to_string()))?;
+        self.make_stream(file);
+        let streams = self.streams.lock().unwrap();
+        let writer = &streams[&file];
+        if *writer.offset_used.lock().unwrap() {
+            return Err(PyValueError::new_err(
+                "set_cipher cannot be combined with pipe_at: ChaCha20 requires strictly in-order encryption",
+            ));
+        }
+        *writer.cipher.lock().unwrap() = Some(cipher);
+        Ok(())
+    }
+
+    fn stream_error(&self, file: FileDescriptor) -> Option<io::Error> {
+        let streams = self.streams.lock().unwrap();
+        let error = streams.get(&file)?.error.lock().unwrap();
+        error.as_ref().map(|err| io::Error::new(err.kind(), err.to_string()))
+    }
+
+    fn check_stream_error(&self, file: FileDescriptor) -> PyResult<()> {
+        match self.stream_error(file) {
+            Some(err) => Err(PyIOError::new_err(err.to_string())),
+            None => Ok(()),
+        }
     }
 
-    pub fn pipe(&self, data: Pointer, size: usize, file: FileDescriptor) {
+    fn check_all_errors(&self) -> PyResult<()> {
+        let files: Vec<FileDescriptor> = self.streams.lock().unwrap().keys().copied().collect();
+        for file in files {
+            self.check_stream_error(file)?;
+        }
+        Ok(())
+    }
+
+    pub fn pipe(&self, data: Pointer, size: usize, file: FileDescriptor, offset: Option<u64>) -> PyResult<()> {
         self.make_stream(file);
+        self.check_stream_error(file)?;
+        // Reader threads never block on a full writer channel (see
+        // `eternal_reader`), so the backpressure happens here: wait while
+        // this fd's backlog is already at capacity.
+        let backlog = self.streams.lock().unwrap().get(&file).map(|w| w.backlog.clone());
+        if let Some(backlog) = backlog {
+            while backlog.lock().unwrap().len() >= (*QUEUE_CAPACITY).max(1) {
+                thread::yield_now();
+            }
+        }
+        if offset.is_some() {
+            let streams = self.streams.lock().unwrap();
+            let writer = &streams[&file];
+            if writer.cipher.lock().unwrap().is_some() {
+                return Err(PyValueError::new_err(
+                    "pipe_at cannot be combined with set_cipher: ChaCha20 requires strictly in-order encryption",
+                ));
+            }
+            *writer.offset_used.lock().unwrap() = true;
+        }
         loop {
             let mut p = self.pending.lock().unwrap();
             if p.values().any(|s| s.contains(&data)) {
@@ -107,10 +354,15 @@ impl TurboPipe {
             p.entry(file).or_insert_with(HashSet::new).insert(data);
             break;
         }
-        self.queue.send(Work { data, size, file }).unwrap();
+        self.queue.send(Work { data, size, file, offset }).unwrap();
+        Ok(())
     }
 
-    pub fn sync(&self) {
+    fn queue_depth(&self) -> usize {
+        self.queue.len()
+    }
+
+    pub fn sync(&self) -> PyResult<()> {
         loop {
             let p = self.pending.lock().unwrap();
             if p.values().all(|s| s.is_empty()) {
@@ -119,9 +371,10 @@ impl TurboPipe {
             drop(p);
             thread::yield_now();
         }
+        self.check_all_errors()
     }
 
-    pub fn close(&self, file: FileDescriptor) {
+    pub fn close(&self, file: FileDescriptor) -> PyResult<()> {
         loop {
             let p = self.pending.lock().unwrap();
             if p.get(&file).map_or(true, |s| s.is_empty()) {
@@ -131,42 +384,242 @@ impl TurboPipe {
             thread::yield_now();
         }
         let mut streams = self.streams.lock().unwrap();
-        if let Some(w) = streams.remove(&file) {
-            drop(w.sender);
-            w.handle.join().unwrap();
+        let writer = streams.remove(&file);
+        drop(streams);
+        let Some(w) = writer else {
+            return Ok(());
+        };
+        // `pending` only tracks frames not yet handed to the writer channel,
+        // so frames can still be sitting there, unwritten, when we reach
+        // this point. Hold the same `error` Arc the writer thread updates
+        // and sample it only after `join()` returns, once the writer has
+        // drained (and possibly failed on) everything still queued.
+        let error = w.error.clone();
+        drop(w.sender);
+        w.handle.join().unwrap();
+        let result = match error.lock().unwrap().as_ref() {
+            Some(err) => Err(PyIOError::new_err(err.to_string())),
+            None => Ok(()),
+        };
+        result
+    }
+
+    fn read_loop(file: FileDescriptor, tx: Sender<Vec<u8>>, error: Arc<Mutex<Option<io::Error>>>) {
+        let mut file = unsafe { File::from_raw_fd(file) };
+        let mut buf = vec![0u8; 64 * 1024];
+        loop {
+            match file.read(&mut buf) {
+                Ok(0) => break,
+                Ok(n) => if tx.send(buf[..n].to_vec()).is_err() {
+                    break;
+                },
+                Err(err) => {
+                    *error.lock().unwrap() = Some(err);
+                    break;
+                }
+            }
+        }
+        // Python owns this fd; don't close it when the reader thread exits.
+        std::mem::forget(file);
+    }
+
+    /// Spawns a background thread tailing `file` into a bounded ring of
+    /// chunks, so `read`/`read_fill` never touch the fd directly from the
+    /// calling (GIL-holding) thread. A no-op if `file` is already open.
+    pub fn read_open(&self, file: FileDescriptor) {
+        let mut readers = self.readers.lock().unwrap();
+        if readers.contains_key(&file) {
+            return;
+        }
+        let (tx, rx) = bounded(*QUEUE_CAPACITY);
+        let error = Arc::new(Mutex::new(None));
+        let handle = thread::spawn({
+            let error = error.clone();
+            move || Self::read_loop(file, tx, error)
+        });
+        readers.insert(file, FrameReader {receiver: rx, handle, leftover: Vec::new(), error});
+    }
+
+    /// Stops tailing `file` and drops its buffered state, so a reused fd
+    /// number doesn't keep reading from the old stream.
+    ///
+    /// `read_loop` may be parked in a blocking `file.read()` syscall with no
+    /// data arriving (an idle producer that hasn't closed the fd); dropping
+    /// the channel alone doesn't wake it, and there's no portable way to
+    /// interrupt that read from here. This call can therefore block for as
+    /// long as the producer stays quiet — callers must keep it off the
+    /// GIL-holding thread.
+    pub fn read_close(&self, file: FileDescriptor) {
+        let mut readers = self.readers.lock().unwrap();
+        let reader = readers.remove(&file);
+        drop(readers);
+        if let Some(reader) = reader {
+            drop(reader.receiver);
+            reader.handle.join().unwrap();
+        }
+    }
+
+    fn get_reader<'a>(readers: &'a mut HashMap<FileDescriptor, FrameReader>, file: FileDescriptor) -> PyResult<&'a mut FrameReader> {
+        readers.get_mut(&file).ok_or_else(|| PyIOError::new_err("file descriptor is not open for reading; call read_open first"))
+    }
+
+    fn check_reader_error(reader: &FrameReader) -> PyResult<()> {
+        match reader.error.lock().unwrap().as_ref() {
+            Some(err) => Err(PyIOError::new_err(err.to_string())),
+            None => Ok(()),
+        }
+    }
+
+    /// Copies already-available bytes into `out`, returning how many were
+    /// written (0 meaning nothing is ready right now). Never blocks.
+    pub fn read(&self, file: FileDescriptor, out: &mut [u8]) -> PyResult<usize> {
+        let mut readers = self.readers.lock().unwrap();
+        let reader = Self::get_reader(&mut readers, file)?;
+        let mut written = 0;
+        while written < out.len() {
+            if reader.leftover.is_empty() {
+                match reader.receiver.try_recv() {
+                    Ok(chunk) => reader.leftover = chunk,
+                    Err(_) => break,
+                }
+            }
+            let take = (out.len() - written).min(reader.leftover.len());
+            out[written..written + take].copy_from_slice(&reader.leftover[..take]);
+            reader.leftover.drain(..take);
+            written += take;
         }
+        if written == 0 {
+            Self::check_reader_error(reader)?;
+        }
+        Ok(written)
+    }
+
+    /// Blocks (releasing the GIL, via the caller) until at least one byte is
+    /// available, mirroring `BufRead::fill_buf` semantics where an empty
+    /// result means EOF. Returns how many bytes are buffered and ready.
+    pub fn read_fill(&self, file: FileDescriptor) -> PyResult<usize> {
+        let mut readers = self.readers.lock().unwrap();
+        let reader = Self::get_reader(&mut readers, file)?;
+        if reader.leftover.is_empty() {
+            match reader.receiver.recv() {
+                Ok(chunk) => reader.leftover = chunk,
+                Err(_) => {
+                    Self::check_reader_error(reader)?;
+                    return Ok(0);
+                }
+            }
+        }
+        Ok(reader.leftover.len())
     }
 }
 
 static TURBOPIPE: Lazy<TurboPipe> = Lazy::new(TurboPipe::new);
 
 #[pyfunction]
-fn pipe(view: Bound<'_, PyMemoryView>, file: FileDescriptor) -> PyResult<()> {
+fn pipe(py: Python<'_>, view: Bound<'_, PyMemoryView>, file: FileDescriptor) -> PyResult<()> {
     let buffer: PyBuffer<u8> = PyBuffer::get(&view)?;
-    TURBOPIPE.pipe(
-        buffer.buf_ptr() as Pointer,
-        buffer.len_bytes(),
-        file
-    );
-    Ok(())
+    let data = buffer.buf_ptr() as Pointer;
+    let size = buffer.len_bytes();
+    // The queues are bounded, so this may block on a slow consumer; release
+    // the GIL for that wait like any other blocking I/O call.
+    py.allow_threads(|| TURBOPIPE.pipe(data, size, file, None))
+}
+
+/// Like `pipe`, but writes `view` at `offset` bytes into `file` instead of
+/// appending, so frames targeting distinct regions can arrive out of order.
+#[pyfunction]
+fn pipe_at(py: Python<'_>, view: Bound<'_, PyMemoryView>, file: FileDescriptor, offset: u64) -> PyResult<()> {
+    let buffer: PyBuffer<u8> = PyBuffer::get(&view)?;
+    let data = buffer.buf_ptr() as Pointer;
+    let size = buffer.len_bytes();
+    py.allow_threads(|| TURBOPIPE.pipe(data, size, file, Some(offset)))
+}
+
+/// Connects to `host:port` and registers it as a streaming sink, returning
+/// the fd to pass to the regular `pipe`/`sync`/`close` calls. Connecting can
+/// stall (an unreachable host, a firewall dropping SYNs), so this releases
+/// the GIL for the duration of the connect like the module's other blocking
+/// calls.
+#[pyfunction]
+fn pipe_to_socket(py: Python<'_>, host: &str, port: u16) -> PyResult<FileDescriptor> {
+    py.allow_threads(|| TURBOPIPE.pipe_to_socket(host, port))
+}
+
+/// Enables on-the-fly ChaCha20 encryption of every frame subsequently piped
+/// to `file`, so the renderer never has to pay for a separate Python-side
+/// encryption pass. `key` must be 32 bytes and `nonce` 12 bytes.
+#[pyfunction]
+fn set_cipher(file: FileDescriptor, key: &[u8], nonce: &[u8]) -> PyResult<()> {
+    TURBOPIPE.set_cipher(file, key, nonce)
 }
 
 #[pyfunction]
 fn sync() -> PyResult<()> {
-    TURBOPIPE.sync();
-    Ok(())
+    TURBOPIPE.sync()
 }
 
 #[pyfunction]
 fn close(file: FileDescriptor) -> PyResult<()> {
-    TURBOPIPE.close(file);
+    TURBOPIPE.close(file)
+}
+
+/// Returns the number of frames currently queued for dispatch, so callers
+/// can tune `TURBOPIPE_QUEUE_CAPACITY` for their workload.
+#[pyfunction]
+fn stats() -> PyResult<usize> {
+    Ok(TURBOPIPE.queue_depth())
+}
+
+/// Starts tailing `file` in the background so `read`/`read_fill` have
+/// somewhere to pull bytes from. Symmetric with `pipe`'s implicit stream
+/// creation, but explicit since a read has no first call to piggyback on.
+#[pyfunction]
+fn read_open(file: FileDescriptor) -> PyResult<()> {
+    TURBOPIPE.read_open(file);
     Ok(())
 }
 
+/// Stops tailing `file`, so its fd number can be safely reused for a later
+/// `read_open` call. Can block for as long as the producer stays quiet
+/// without closing the fd (see `TurboPipe::read_close`), so this releases
+/// the GIL for the wait.
+#[pyfunction]
+fn read_close(py: Python<'_>, file: FileDescriptor) -> PyResult<()> {
+    py.allow_threads(|| TURBOPIPE.read_close(file));
+    Ok(())
+}
+
+/// Copies whatever bytes are already buffered for `file` into `view`,
+/// returning the count (0 if nothing is ready yet). Non-blocking.
+#[pyfunction]
+fn read(file: FileDescriptor, view: Bound<'_, PyMemoryView>) -> PyResult<usize> {
+    let buffer: PyBuffer<u8> = PyBuffer::get(&view)?;
+    if buffer.readonly() {
+        return Err(PyValueError::new_err("read() requires a writable memoryview"));
+    }
+    let out = unsafe { std::slice::from_raw_parts_mut(buffer.buf_ptr() as *mut u8, buffer.len_bytes()) };
+    TURBOPIPE.read(file, out)
+}
+
+/// Blocks until at least one byte of `file` is available, returning how
+/// many are buffered and ready (0 == EOF), matching `fill_buf` semantics.
+#[pyfunction]
+fn read_fill(py: Python<'_>, file: FileDescriptor) -> PyResult<usize> {
+    py.allow_threads(|| TURBOPIPE.read_fill(file))
+}
+
 #[pymodule]
 fn turbopipe(module: &Bound<'_, PyModule>) -> PyResult<()> {
     module.add_function(wrap_pyfunction!(pipe, module)?)?;
+    module.add_function(wrap_pyfunction!(pipe_at, module)?)?;
+    module.add_function(wrap_pyfunction!(pipe_to_socket, module)?)?;
+    module.add_function(wrap_pyfunction!(set_cipher, module)?)?;
     module.add_function(wrap_pyfunction!(sync, module)?)?;
     module.add_function(wrap_pyfunction!(close, module)?)?;
+    module.add_function(wrap_pyfunction!(stats, module)?)?;
+    module.add_function(wrap_pyfunction!(read_open, module)?)?;
+    module.add_function(wrap_pyfunction!(read_close, module)?)?;
+    module.add_function(wrap_pyfunction!(read, module)?)?;
+    module.add_function(wrap_pyfunction!(read_fill, module)?)?;
     Ok(())
 }
\ No newline at end of file